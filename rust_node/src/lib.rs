@@ -1,3 +1,4 @@
+use pyo3::exceptions::PyIndexError;
 use pyo3::prelude::*;
 
 /// Rust linked list node exposed to Python via PyO3.
@@ -22,6 +23,42 @@ impl RustNode {
     fn new(value: i64, next: Option<Py<RustNode>>) -> Self {
         RustNode { value, next }
     }
+
+    /// Return a `RustNodeIter` cursor rooted at this node, so a chain can be
+    /// consumed from pure Python, e.g. `sum(node)`.
+    fn __iter__(slf: &Bound<'_, Self>) -> RustNodeIter {
+        RustNodeIter {
+            cursor: Some(slf.clone().unbind()),
+        }
+    }
+}
+
+/// Iterator cursor over a `RustNode` chain, driven from Python.
+///
+/// Unlike `rust_sum_list`, which loops inside Rust and crosses the boundary
+/// once, each `__next__` call here is its own boundary crossing: Python's
+/// interpreter loop invokes this once per element, fetching a single `i64`
+/// each time. This gives the benchmark a third crossing pattern alongside
+/// Rust-pulls-everything (`rust_sum_list`) and Rust-owns-everything
+/// (`RustArenaList`).
+#[pyclass]
+struct RustNodeIter {
+    cursor: Option<Py<RustNode>>,
+}
+
+#[pymethods]
+impl RustNodeIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> Option<i64> {
+        let current = self.cursor.take()?;
+        let node = current.bind(py).get();
+        let value = node.value;
+        self.cursor = node.next.as_ref().map(|next| next.clone_ref(py));
+        Some(value)
+    }
 }
 
 /// Sum all values in a RustNode linked list.
@@ -62,9 +99,316 @@ fn rust_sum_list(head: &Bound<'_, PyAny>) -> PyResult<i64> {
     Ok(total)
 }
 
+/// Sum all values in a RustNode linked list without touching reference counts.
+///
+/// `head` is borrowed for the whole function, so every node reachable from it
+/// is guaranteed alive for the duration of that borrow: we never need an
+/// owned `Py<RustNode>` to keep a node alive, only a `&RustNode` borrowed
+/// from its parent. `Py::bind` hands back a `&Bound<'py, RustNode>` whose
+/// lifetime is tied to the `Py<RustNode>` it came from, which in turn is
+/// tied back to `head` — so the whole chain of `&RustNode` references shares
+/// the GIL lifetime and no `clone_ref`/drop is ever emitted.
+///
+/// This isolates the cost of reference counting from the cost of the
+/// boundary itself: compare against `rust_sum_list`, which pays one
+/// `clone_ref` (INCREF) and one drop (DECREF) per node.
+#[pyfunction]
+fn rust_sum_list_borrowed(head: &Bound<'_, PyAny>) -> PyResult<i64> {
+    let py = head.py();
+    let mut total: i64 = 0;
+
+    if head.is_none() {
+        return Ok(0);
+    }
+
+    let first: &Bound<'_, RustNode> = head.cast()?;
+    let mut node: &RustNode = first.get();
+
+    loop {
+        total += node.value;
+
+        node = match node.next.as_ref() {
+            Some(next) => next.bind(py).get(),
+            None => break,
+        };
+    }
+
+    Ok(total)
+}
+
+/// Rust linked list node identical to `RustNode`, but *not* frozen.
+///
+/// This forces PyO3's runtime borrow flag (an `AtomicUsize` compare-and-swap
+/// per access) on every `try_borrow()`, so summing a list of these quantifies
+/// exactly how much the `frozen` fast path saves versus `RustNode::get()`.
+#[pyclass]
+struct RustNodeTracked {
+    #[pyo3(get)]
+    value: i64,
+    #[pyo3(get)]
+    next: Option<Py<RustNodeTracked>>,
+}
+
+#[pymethods]
+impl RustNodeTracked {
+    #[new]
+    #[pyo3(signature = (value, next=None))]
+    fn new(value: i64, next: Option<Py<RustNodeTracked>>) -> Self {
+        RustNodeTracked { value, next }
+    }
+}
+
+/// Sum all values in a `RustNodeTracked` linked list.
+///
+/// Same traversal shape as `rust_sum_list`, but each node is read through
+/// `try_borrow()` instead of `get()`, so every access pays the runtime
+/// borrow-flag CAS that `frozen` would otherwise eliminate.
+#[pyfunction]
+fn rust_sum_list_tracked(head: &Bound<'_, PyAny>) -> PyResult<i64> {
+    let py = head.py();
+    let mut total: i64 = 0;
+
+    if head.is_none() {
+        return Ok(0);
+    }
+
+    let first: &Bound<'_, RustNodeTracked> = head.cast()?;
+    let mut current: Py<RustNodeTracked> = first.clone().unbind();
+
+    loop {
+        let node: PyRef<'_, RustNodeTracked> = current.bind(py).try_borrow()?;
+        total += node.value;
+
+        let next_owned = match node.next {
+            Some(ref next) => next.clone_ref(py),
+            None => break,
+        };
+        drop(node);
+        current = next_owned;
+    }
+
+    Ok(total)
+}
+
+/// A single link in `RustArenaList`'s backing store.
+///
+/// `next` is an index into the arena rather than a `Py<RustNode>`, so
+/// advancing never touches a Python reference count.
+struct ArenaNode {
+    value: i64,
+    next: Option<usize>,
+}
+
+/// Linked list that owns its entire chain inside Rust as a contiguous arena.
+///
+/// Unlike `RustNode`, where every link is a `Py<RustNode>` and traversal
+/// crosses the boundary once per node, `RustArenaList` builds its whole
+/// chain from a Python iterable exactly once at construction time and from
+/// then on traverses `Vec<ArenaNode>` with plain indices — mirroring the
+/// self-referential-without-`unsafe` pattern used by owning containers like
+/// `OwningDirstateMap` (index-based links instead of raw pointers). `sum()`
+/// crosses the boundary exactly once, giving the benchmark its "amortized
+/// boundary" baseline against the per-node `Py<RustNode>` design.
+#[pyclass(frozen)]
+struct RustArenaList {
+    nodes: Vec<ArenaNode>,
+}
+
+#[pymethods]
+impl RustArenaList {
+    #[new]
+    fn new(values: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let values: Vec<i64> = values
+            .try_iter()?
+            .map(|item| item?.extract::<i64>())
+            .collect::<PyResult<_>>()?;
+
+        let len = values.len();
+        let nodes = values
+            .into_iter()
+            .enumerate()
+            .map(|(index, value)| ArenaNode {
+                value,
+                next: if index + 1 < len { Some(index + 1) } else { None },
+            })
+            .collect();
+
+        Ok(RustArenaList { nodes })
+    }
+
+    /// Sum all values in the arena. The only boundary crossing is this call.
+    fn sum(&self) -> i64 {
+        let mut total: i64 = 0;
+        let mut cursor = if self.nodes.is_empty() { None } else { Some(0usize) };
+
+        while let Some(index) = cursor {
+            let node = &self.nodes[index];
+            total += node.value;
+            cursor = node.next;
+        }
+
+        total
+    }
+
+    /// Value at `index`, for parity with `RustNode.value`.
+    fn value(&self, index: usize) -> PyResult<i64> {
+        self.nodes
+            .get(index)
+            .map(|node| node.value)
+            .ok_or_else(|| PyIndexError::new_err("RustArenaList index out of range"))
+    }
+
+    /// Index of the node following `index`, for parity with `RustNode.next`.
+    fn next(&self, index: usize) -> PyResult<Option<usize>> {
+        self.nodes
+            .get(index)
+            .map(|node| node.next)
+            .ok_or_else(|| PyIndexError::new_err("RustArenaList index out of range"))
+    }
+
+    fn __len__(&self) -> usize {
+        self.nodes.len()
+    }
+}
+
+/// Variant of `rust_sum_list` for builds with PyO3's global reference pool
+/// disabled (`--cfg pyo3_disable_reference_pool`, optionally paired with
+/// `--cfg pyo3_leak_on_drop_without_reference_pool`).
+///
+/// The reference pool normally defers `Py<T>` drops that happen without the
+/// GIL; with it disabled, dropping a `Py<RustNode>` without the GIL aborts
+/// (or leaks, under the leak-on-drop flag) instead. This traversal never
+/// triggers that path: `current` is only ever dropped — via reassignment —
+/// inside this function's body, and `head.py()` proves the GIL is held for
+/// the function's entire lifetime. Comparing this against `rust_sum_list`
+/// under the same workload quantifies how much of the per-node
+/// `clone_ref`/drop cost is pool bookkeeping versus raw INCREF/DECREF.
+#[cfg(pyo3_disable_reference_pool)]
+#[pyfunction]
+fn rust_sum_list_no_pool(head: &Bound<'_, PyAny>) -> PyResult<i64> {
+    let py = head.py();
+    let mut total: i64 = 0;
+
+    if head.is_none() {
+        return Ok(0);
+    }
+
+    let first: &Bound<'_, RustNode> = head.cast()?;
+    let mut current: Py<RustNode> = first.clone().unbind();
+
+    loop {
+        let node: &RustNode = current.bind(py).get();
+        total += node.value;
+
+        match node.next {
+            Some(ref next) => {
+                // Reassigning `current` drops the old handle here, with the
+                // GIL held for the whole call — the invariant this build
+                // mode requires.
+                current = next.clone_ref(py);
+            }
+            None => break,
+        }
+    }
+
+    Ok(total)
+}
+
 #[pymodule]
 fn rust_node(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<RustNode>()?;
+    m.add_class::<RustNodeIter>()?;
+    m.add_class::<RustNodeTracked>()?;
+    m.add_class::<RustArenaList>()?;
     m.add_function(wrap_pyfunction!(rust_sum_list, m)?)?;
+    m.add_function(wrap_pyfunction!(rust_sum_list_borrowed, m)?)?;
+    #[cfg(pyo3_disable_reference_pool)]
+    m.add_function(wrap_pyfunction!(rust_sum_list_no_pool, m)?)?;
+    m.add_function(wrap_pyfunction!(rust_sum_list_tracked, m)?)?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Build a `RustNode` chain `len, len-1, ..., 1` and return its head.
+    fn build_plain(py: Python<'_>, len: i64) -> Py<RustNode> {
+        let mut next: Option<Py<RustNode>> = None;
+        for value in 1..=len {
+            next = Some(Py::new(py, RustNode::new(value, next)).unwrap());
+        }
+        next.unwrap()
+    }
+
+    /// Build a `RustNodeTracked` chain `len, len-1, ..., 1` and return its head.
+    fn build_tracked(py: Python<'_>, len: i64) -> Py<RustNodeTracked> {
+        let mut next: Option<Py<RustNodeTracked>> = None;
+        for value in 1..=len {
+            next = Some(Py::new(py, RustNodeTracked::new(value, next)).unwrap());
+        }
+        next.unwrap()
+    }
+
+    #[test]
+    fn tracked_and_plain_sums_agree() {
+        Python::attach(|py| {
+            let plain_head = build_plain(py, 100);
+            let tracked_head = build_tracked(py, 100);
+
+            let plain_sum = rust_sum_list(plain_head.bind(py)).unwrap();
+            let tracked_sum = rust_sum_list_tracked(tracked_head.bind(py)).unwrap();
+
+            assert_eq!(plain_sum, tracked_sum);
+        });
+    }
+
+    #[test]
+    fn borrowed_and_owning_sums_agree() {
+        Python::attach(|py| {
+            let head = build_plain(py, 100);
+
+            let owning_sum = rust_sum_list(head.bind(py)).unwrap();
+            let borrowed_sum = rust_sum_list_borrowed(head.bind(py)).unwrap();
+
+            assert_eq!(owning_sum, borrowed_sum);
+        });
+    }
+
+    #[test]
+    fn node_iter_matches_rust_sum_list_and_stops_cleanly() {
+        Python::attach(|py| {
+            let head = build_plain(py, 5);
+            let expected_sum = rust_sum_list(head.bind(py)).unwrap();
+
+            let mut iter = RustNode::__iter__(head.bind(py));
+            let mut values = Vec::new();
+            while let Some(value) = iter.__next__(py) {
+                values.push(value);
+            }
+
+            assert_eq!(values, vec![5, 4, 3, 2, 1]);
+            assert_eq!(values.iter().sum::<i64>(), expected_sum);
+
+            // Exhausted iterator keeps yielding None rather than panicking.
+            assert_eq!(iter.__next__(py), None);
+        });
+    }
+
+    #[test]
+    fn arena_list_sum_value_and_next_are_consistent() {
+        Python::attach(|py| {
+            let values = pyo3::types::PyList::new(py, [10_i64, 20, 30]).unwrap();
+            let list = RustArenaList::new(values.as_any()).unwrap();
+
+            assert_eq!(list.sum(), 60);
+            assert_eq!(list.value(0).unwrap(), 10);
+            assert_eq!(list.value(1).unwrap(), 20);
+            assert_eq!(list.value(2).unwrap(), 30);
+            assert_eq!(list.next(0).unwrap(), Some(1));
+            assert_eq!(list.next(1).unwrap(), Some(2));
+            assert_eq!(list.next(2).unwrap(), None);
+            assert!(list.value(3).is_err());
+        });
+    }
+}